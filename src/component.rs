@@ -1,11 +1,12 @@
 use core::any::Any;
+use core::cell::RefCell;
 
 use alloc::boxed::Box;
 use alloc::vec::Vec;
-use web_sys::Element;
+use web_sys::{Element, Node};
 
 use crate::attribute::Attributes;
-use crate::signal::Value;
+use crate::signal::{DropUnsubscriber, Signal, Value};
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub struct ElementNotFoundError;
@@ -53,6 +54,62 @@ impl Component {
         self.element.append_child(&component.element).unwrap();
         self
     }
+
+    /// Keeps this component's children in sync with `signal`'s `Vec`,
+    /// re-rendering only what changed: existing elements are reused for keys
+    /// that are still present, `render` is called for newly-appeared keys,
+    /// and elements for keys that disappeared are removed. Order is patched
+    /// with `insert_before` rather than rebuilding the subtree from scratch.
+    pub fn children_signal<K, F>(mut self, signal: Signal<Vec<K>>, mut render: F) -> Self
+    where
+        K: PartialEq + Clone + 'static,
+        F: FnMut(&K) -> Component + 'static,
+    {
+        let parent = self.element.clone();
+        let rendered: RefCell<Vec<(K, Component)>> = RefCell::new(Vec::new());
+
+        let unsub = signal.for_each(move |keys| {
+            let mut rendered = rendered.borrow_mut();
+            let mut next = Vec::with_capacity(keys.len());
+
+            for key in keys {
+                let component = match rendered.iter().position(|(k, _)| k == key) {
+                    Some(index) => rendered.remove(index).1,
+                    None => {
+                        let component = render(key);
+                        parent.append_child(component.as_element()).unwrap();
+                        component
+                    }
+                };
+                next.push((key.clone(), component));
+            }
+
+            for (_, stale) in rendered.drain(..) {
+                parent.remove_child(stale.as_element()).unwrap();
+            }
+
+            let mut next_sibling: Option<Element> = None;
+            for (_, component) in next.iter().rev() {
+                let element = component.as_element();
+                let reference: Option<&Node> = next_sibling.as_ref().map(AsRef::as_ref);
+
+                let already_positioned = match element.next_sibling() {
+                    Some(actual) => actual.is_same_node(reference),
+                    None => reference.is_none(),
+                };
+                if !already_positioned {
+                    parent.insert_before(element, reference).unwrap();
+                }
+
+                next_sibling = Some(element.clone());
+            }
+
+            *rendered = next;
+        });
+
+        self.dependencies.push(Box::new(DropUnsubscriber(unsub)));
+        self
+    }
 }
 
 macro_rules! elements {
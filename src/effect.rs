@@ -0,0 +1,129 @@
+use core::any::Any;
+use core::cell::RefCell;
+
+use alloc::boxed::Box;
+use alloc::rc::{Rc, Weak};
+use alloc::vec::Vec;
+
+use crate::signal::{Computed, Mutable};
+
+/// Asserts that `T` is safe to share across threads, for statics holding
+/// otherwise-`!Sync` state. Sound here because this crate targets WASM,
+/// which is single-threaded — the same assumption `RawSignal` relies on in
+/// using plain `Cell`/`RefCell` rather than their atomic equivalents.
+struct AssertSync<T>(T);
+
+unsafe impl<T> Sync for AssertSync<T> {}
+
+static OBSERVERS: AssertSync<RefCell<Vec<Rc<dyn Observer>>>> =
+    AssertSync(RefCell::new(Vec::new()));
+
+/// Registered by [`Signal::track`](crate::signal::Signal) with whichever
+/// [`effect`]/[`memo`] is currently running, so that reading a signal from
+/// inside one makes it a dependency.
+pub(crate) trait Observer {
+    /// Re-runs the effect this observer belongs to.
+    fn notify(&self);
+
+    /// Keeps a dependency's subscription alive until the next run.
+    fn record(&self, dependency: Box<dyn Any>);
+
+    /// Returns whether `signal` (identified by its `Rc` address) hasn't
+    /// already been read during the current run, marking it as seen either
+    /// way. Lets [`Signal::track`](crate::signal::Signal::track) skip
+    /// subscribing twice when the same signal is read more than once in one
+    /// run, which would otherwise re-run this effect once per read instead
+    /// of once per actual change.
+    fn mark_seen(&self, signal: *const ()) -> bool;
+}
+
+pub(crate) fn current_observer() -> Option<Rc<dyn Observer>> {
+    OBSERVERS.0.borrow().last().cloned()
+}
+
+struct EffectInner {
+    self_weak: Weak<EffectInner>,
+    body: RefCell<Box<dyn FnMut()>>,
+    dependencies: RefCell<Vec<Box<dyn Any>>>,
+    /// Addresses of the signals already subscribed to during the current
+    /// run; see [`Observer::mark_seen`].
+    seen: RefCell<Vec<*const ()>>,
+}
+
+impl EffectInner {
+    fn new(body: impl FnMut() + 'static) -> Rc<Self> {
+        Rc::new_cyclic(|self_weak| Self {
+            self_weak: self_weak.clone(),
+            body: RefCell::new(Box::new(body)),
+            dependencies: RefCell::new(Vec::new()),
+            seen: RefCell::new(Vec::new()),
+        })
+    }
+
+    fn run(self: &Rc<Self>) {
+        self.dependencies.borrow_mut().clear();
+        self.seen.borrow_mut().clear();
+
+        OBSERVERS.0.borrow_mut().push(self.clone());
+        (self.body.borrow_mut())();
+        OBSERVERS.0.borrow_mut().pop();
+    }
+}
+
+impl Observer for EffectInner {
+    fn notify(&self) {
+        if let Some(this) = self.self_weak.upgrade() {
+            this.run();
+        }
+    }
+
+    fn record(&self, dependency: Box<dyn Any>) {
+        self.dependencies.borrow_mut().push(dependency);
+    }
+
+    fn mark_seen(&self, signal: *const ()) -> bool {
+        let mut seen = self.seen.borrow_mut();
+        if seen.contains(&signal) {
+            false
+        } else {
+            seen.push(signal);
+            true
+        }
+    }
+}
+
+/// A handle to a running [`effect`]. Dropping it unsubscribes the effect from
+/// every signal it was depending on and stops it from re-running.
+#[must_use = "dropping the Effect stops it from re-running"]
+pub struct Effect(Rc<EffectInner>);
+
+/// Runs `body` once immediately, then automatically re-runs it every time a
+/// signal it read (via `Signal::get`/`try_get`, anywhere in the call graph)
+/// changes. Dependencies are re-tracked on every run, so branches where they
+/// change between runs are handled correctly.
+pub fn effect(body: impl FnMut() + 'static) -> Effect {
+    let inner = EffectInner::new(body);
+    inner.run();
+    Effect(inner)
+}
+
+/// Derives a [`Computed`] signal whose value is `f()`, automatically
+/// recomputed whenever any signal `f` reads changes — unlike
+/// [`Signal::map`](crate::signal::Signal::map), the dependencies don't need to
+/// be named up front.
+pub fn memo<T: 'static>(mut f: impl FnMut() -> T + 'static) -> Computed<T> {
+    let value = Mutable::uninit();
+    let weak = value.downgrade();
+
+    // The effect's body must only hold a weak reference back to `value`: it
+    // is itself kept alive by `value` below, so a strong reference here
+    // would be a reference cycle that leaks the signal forever.
+    let eff = effect(move || {
+        if let Some(raw) = weak.upgrade() {
+            let _ = raw.try_set(f());
+        }
+    });
+    value.keep_alive(eff);
+
+    Computed::from_signal((*value).clone())
+}
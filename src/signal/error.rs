@@ -0,0 +1,22 @@
+use core::fmt;
+
+/// The result type used throughout the signal module.
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// An error returned when reading or writing a [`Signal`](super::Signal) fails.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Error {
+    /// The signal has not been given a value yet, see [`Mutable::uninit`](super::Mutable::uninit).
+    Uninitialized,
+    /// The signal is already being mutated, or is currently notifying its subscribers.
+    Busy,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Uninitialized => write!(f, "signal has not been initialized"),
+            Self::Busy => write!(f, "signal is already being updated"),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use core::cell::RefCell;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+
+use alloc::collections::VecDeque;
+use alloc::rc::{Rc, Weak};
+
+use futures_core::Stream;
+
+use super::raw::RawSignal;
+use super::{DropUnsubscriber, Signal, Unsubscriber};
+
+impl<T: 'static> Signal<T> {
+    /// Resolves the next time this signal's value changes after the returned
+    /// future is first polled; changes made before that first poll are not
+    /// seen. Unlike [`Signal::stream`], this does not fire for the value the
+    /// signal already holds.
+    pub fn changed(&self) -> Changed<T> {
+        Changed {
+            signal: Rc::downgrade(self.inner()),
+            state: Rc::new(RefCell::new(ChangedState {
+                fired: false,
+                waker: None,
+                unsub: None,
+            })),
+        }
+    }
+}
+
+impl<T: Clone + 'static> Signal<T> {
+    /// Streams every new value of this signal as it changes, starting with
+    /// the value it holds when first polled.
+    pub fn stream(&self) -> ValueStream<T> {
+        ValueStream {
+            signal: Rc::downgrade(self.inner()),
+            state: Rc::new(RefCell::new(StreamState {
+                buffer: VecDeque::new(),
+                waker: None,
+                unsub: None,
+            })),
+        }
+    }
+}
+
+struct ChangedState<T: 'static> {
+    fired: bool,
+    waker: Option<Waker>,
+    unsub: Option<DropUnsubscriber<T>>,
+}
+
+pub struct Changed<T: 'static> {
+    signal: Weak<RawSignal<T>>,
+    state: Rc<RefCell<ChangedState<T>>>,
+}
+
+impl<T: 'static> Future for Changed<T> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.state.borrow_mut();
+
+        if state.fired {
+            return Poll::Ready(());
+        }
+
+        state.waker = Some(cx.waker().clone());
+
+        if state.unsub.is_none() {
+            if let Some(raw) = self.signal.upgrade() {
+                let weak_state = Rc::downgrade(&self.state);
+                let id = raw.subscribe_silent(move |_| {
+                    if let Some(state) = weak_state.upgrade() {
+                        let mut state = state.borrow_mut();
+                        state.fired = true;
+                        state.unsub = None;
+                        if let Some(waker) = state.waker.take() {
+                            waker.wake();
+                        }
+                    }
+                });
+                state.unsub = Some(DropUnsubscriber(Unsubscriber::new(self.signal.clone(), id)));
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+struct StreamState<T: 'static> {
+    buffer: VecDeque<T>,
+    waker: Option<Waker>,
+    unsub: Option<DropUnsubscriber<T>>,
+}
+
+pub struct ValueStream<T: 'static> {
+    signal: Weak<RawSignal<T>>,
+    state: Rc<RefCell<StreamState<T>>>,
+}
+
+impl<T: Clone + 'static> Stream for ValueStream<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        {
+            let mut state = self.state.borrow_mut();
+            if let Some(value) = state.buffer.pop_front() {
+                return Poll::Ready(Some(value));
+            }
+            state.waker = Some(cx.waker().clone());
+        }
+
+        if self.state.borrow().unsub.is_none() {
+            if let Some(signal) = self.signal.upgrade().map(Signal) {
+                let weak_state = Rc::downgrade(&self.state);
+                let unsub = signal.for_each(move |value: &T| {
+                    if let Some(state) = weak_state.upgrade() {
+                        let mut state = state.borrow_mut();
+                        state.buffer.push_back(value.clone());
+                        if let Some(waker) = state.waker.take() {
+                            waker.wake();
+                        }
+                    }
+                });
+                self.state.borrow_mut().unsub = Some(DropUnsubscriber(unsub));
+            }
+        }
+
+        match self.state.borrow_mut().buffer.pop_front() {
+            Some(value) => Poll::Ready(Some(value)),
+            None => Poll::Pending,
+        }
+    }
+}
@@ -1,15 +1,21 @@
 mod error;
+mod future;
 mod raw;
 mod traits;
 
+use core::any::Any;
 use core::mem;
 use core::ops::{Deref, DerefMut};
 
+use alloc::boxed::Box;
 use alloc::rc::{Rc, Weak};
+use alloc::vec::Vec;
 
 use self::raw::{RawSignal, SubscriberId};
+use crate::effect;
 
 pub use error::*;
+pub use future::*;
 pub use traits::*;
 
 #[repr(transparent)]
@@ -26,14 +32,56 @@ impl<T> Signal<T> {
         &self.0
     }
 
+    /// A weak handle to this signal, upgradable back into one. Used by
+    /// derived signals (e.g. [`effect::memo`](crate::effect::memo)) that must
+    /// not hold a strong reference back to a signal they themselves are kept
+    /// alive by, which would otherwise form a reference cycle.
+    #[inline]
+    pub(crate) fn downgrade(&self) -> Weak<RawSignal<T>> {
+        Rc::downgrade(&self.0)
+    }
+
     #[inline]
     pub fn try_get(&self) -> Result<T>
     where
         T: Clone,
     {
+        self.track();
         self.inner().try_get()
     }
 
+    /// Registers the currently-running [`effect`]/[`memo`](crate::effect::memo),
+    /// if any, as a subscriber of this signal, so that it re-runs the next
+    /// time this signal changes. A no-op if the same signal was already read
+    /// earlier in this run, so reading it more than once doesn't cause the
+    /// effect to re-run more than once per actual change.
+    fn track(&self) {
+        let Some(observer) = effect::current_observer() else {
+            return;
+        };
+
+        if !observer.mark_seen(Rc::as_ptr(self.inner()).cast()) {
+            return;
+        }
+
+        let weak_self = Rc::downgrade(self.inner());
+        let weak_observer = Rc::downgrade(&observer);
+
+        let id = self.inner().subscribe_silent(move |_| {
+            if let Some(observer) = weak_observer.upgrade() {
+                observer.notify();
+            }
+        });
+
+        observer.record(Box::new(DropUnsubscriber(Unsubscriber::new(weak_self, id))));
+    }
+
+    /// Keeps `dependency` alive for as long as this signal is, e.g. the
+    /// [`effect::Effect`] driving a [`memo`](crate::effect::memo).
+    pub(crate) fn keep_alive(&self, dependency: impl Any) {
+        self.inner().keep_alive(dependency);
+    }
+
     #[inline]
     pub fn get(&self) -> T
     where
@@ -69,18 +117,68 @@ impl<T> Signal<T> {
         self.inner().raw_for_each(|_| notify);
     }
 
-    pub fn map<B, F>(&self, map: F) -> Signal<B>
+    pub fn map<B, F>(&self, mut map: F) -> Computed<B>
     where
         F: FnMut(&T) -> B + 'static,
     {
-        todo!()
+        let child = Signal::new_from_raw(RawSignal::uninit());
+        let weak_child = Rc::downgrade(child.inner());
+
+        let unsub = self.for_each(move |value| {
+            if let Some(child) = weak_child.upgrade() {
+                let _ = child.try_set(map(value));
+            }
+        });
+        child.inner().keep_alive(DropUnsubscriber(unsub));
+
+        Computed(child)
     }
 
-    pub fn filter<P>(&self, predicate: P) -> Signal<T>
+    pub fn filter<P>(&self, mut predicate: P) -> Signal<T>
     where
-        P: FnMut(&T) -> bool,
+        T: Clone,
+        P: FnMut(&T) -> bool + 'static,
+    {
+        let child = Signal::new_from_raw(RawSignal::uninit());
+        let weak_child = Rc::downgrade(child.inner());
+
+        let unsub = self.for_each(move |value| {
+            if predicate(value) {
+                if let Some(child) = weak_child.upgrade() {
+                    let _ = child.try_set(value.clone());
+                }
+            }
+        });
+        child.inner().keep_alive(DropUnsubscriber(unsub));
+
+        child
+    }
+
+    /// Derives a signal holding the pair of both signals' current values,
+    /// recomputed whenever either one changes. Stays uninitialized for as
+    /// long as either input is. See the [`combine!`] macro for combining more
+    /// than two signals at once.
+    pub fn zip<B>(&self, other: &Signal<B>) -> Signal<(T, B)>
+    where
+        T: Clone,
+        B: Clone + 'static,
     {
-        todo!()
+        let a = self.clone();
+        let b = other.clone();
+
+        let child = Signal::new_from_raw(RawSignal::uninit());
+        let weak_child = Rc::downgrade(child.inner());
+
+        let eff = effect::effect(move || {
+            if let (Ok(a), Ok(b)) = (a.try_get(), b.try_get()) {
+                if let Some(child) = weak_child.upgrade() {
+                    let _ = child.try_set((a, b));
+                }
+            }
+        });
+        child.inner().keep_alive(eff);
+
+        child
     }
 }
 
@@ -91,6 +189,32 @@ impl<T> Clone for Signal<T> {
     }
 }
 
+impl<T: Clone> Signal<Vec<T>> {
+    /// Derives a signal tracking just the element at `index`, without
+    /// cloning the rest of the vector on every read.
+    pub fn at(&self, index: usize) -> Computed<Option<T>> {
+        self.map(move |values| values.get(index).cloned())
+    }
+
+    /// Derives a signal tracking the vector's length.
+    pub fn len(&self) -> Computed<usize> {
+        self.map(Vec::len)
+    }
+}
+
+impl<T: Clone> Signal<Option<T>> {
+    /// Derives a signal tracking whether this signal currently holds a value.
+    pub fn some(&self) -> Computed<bool> {
+        self.map(Option::is_some)
+    }
+
+    /// Derives a signal tracking this signal's value, or `default` when it
+    /// currently holds none.
+    pub fn unwrap_or(&self, default: T) -> Computed<T> {
+        self.map(move |value| value.clone().unwrap_or_else(|| default.clone()))
+    }
+}
+
 #[repr(transparent)]
 pub struct Mutable<T: 'static>(Signal<T>);
 
@@ -179,6 +303,74 @@ impl<T> From<T> for Mutable<T> {
     }
 }
 
+/// A read-only [`Signal`] derived from one or more other signals, e.g. via
+/// [`Signal::map`] or [`memo`](crate::effect::memo).
+#[repr(transparent)]
+pub struct Computed<T: 'static>(Signal<T>);
+
+impl<T> Computed<T> {
+    #[inline]
+    pub(crate) fn from_signal(signal: Signal<T>) -> Self {
+        Self(signal)
+    }
+
+    #[inline]
+    pub fn try_get(&self) -> Result<T>
+    where
+        T: Clone,
+    {
+        self.0.try_get()
+    }
+
+    #[inline]
+    pub fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        self.0.get()
+    }
+
+    #[inline]
+    pub fn for_each<F>(&self, notify: F) -> Unsubscriber<T>
+    where
+        F: FnMut(&T) + 'static,
+    {
+        self.0.for_each(notify)
+    }
+
+    #[inline]
+    pub fn for_each_inner<F>(&self, notify: F)
+    where
+        F: FnMut(&T, &mut Unsubscriber<T>) + 'static,
+    {
+        self.0.for_each_inner(notify);
+    }
+
+    #[inline]
+    pub fn for_each_forever<F>(&self, notify: F)
+    where
+        F: FnMut(&T) + 'static,
+    {
+        self.0.for_each_forever(notify);
+    }
+}
+
+impl<T> Clone for Computed<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Deref for Computed<T> {
+    type Target = Signal<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
 #[repr(transparent)]
 pub struct Unsubscriber<T>(Option<(Weak<RawSignal<T>>, SubscriberId)>);
 
@@ -246,3 +438,32 @@ impl<T> Drop for DropUnsubscriber<T> {
     }
 }
 
+/// Derives a [`Computed`] from any number of signals, recomputing `$body`
+/// applied to their current values whenever any of them changes. Stays
+/// uninitialized for as long as any input is, like [`Signal::zip`]. For two
+/// signals, prefer [`Signal::zip`].
+///
+/// ```ignore
+/// let total = combine!(|a, b, c| a + b + c, [a, b, c]);
+/// ```
+#[macro_export]
+macro_rules! combine {
+    ($body:expr, [$($signal:ident),+ $(,)?]) => {{
+        $(let $signal = $signal.clone();)+
+
+        let child = Signal::new_from_raw(RawSignal::uninit());
+        let weak_child = Rc::downgrade(child.inner());
+
+        let eff = effect::effect(move || {
+            if let ($(Ok($signal)),+) = ($($signal.try_get()),+) {
+                if let Some(child) = weak_child.upgrade() {
+                    let _ = child.try_set(($body)($($signal),+));
+                }
+            }
+        });
+        child.inner().keep_alive(eff);
+
+        Computed::from_signal(child)
+    }};
+}
+
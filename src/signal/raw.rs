@@ -0,0 +1,255 @@
+use core::any::Any;
+use core::cell::{Cell, RefCell, UnsafeCell};
+use core::num::NonZeroU32;
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use super::error::{Error, Result};
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub(crate) struct SubscriberId(NonZeroU32);
+
+#[derive(Copy, Clone, Debug)]
+enum NotifierState {
+    Active(SubscriberId),
+    Deleted(SubscriberId),
+}
+
+impl NotifierState {
+    #[inline]
+    fn id(self) -> SubscriberId {
+        match self {
+            Self::Active(id) | Self::Deleted(id) => id,
+        }
+    }
+
+    #[inline]
+    fn deleted(self) -> bool {
+        matches!(self, Self::Deleted(_))
+    }
+}
+
+struct Notifier<T> {
+    state: Cell<NotifierState>,
+    notify: *mut dyn FnMut(&T),
+}
+
+impl<T> Drop for Notifier<T> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            let _ = Box::from_raw(self.notify);
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum SignalState {
+    /// The signal is not currently in use.
+    Idling,
+    /// The signal's data is currently being updated and/or its subscribers
+    /// are being notified.
+    Mutating,
+    /// The signal is currently running a newly-registered subscriber with a
+    /// reference to the current data.
+    Subscribing,
+}
+
+pub(crate) struct RawSignal<T> {
+    state: Cell<SignalState>,
+    data: UnsafeCell<Option<T>>,
+    subscribers: UnsafeCell<Vec<Notifier<T>>>,
+    next_id: Cell<NonZeroU32>,
+    needs_delete: Cell<bool>,
+    /// Type-erased handles kept alive for as long as this signal is, e.g. the
+    /// subscription a derived signal depends on; see [`Self::keep_alive`].
+    dependencies: RefCell<Vec<Box<dyn Any>>>,
+}
+
+impl<T> RawSignal<T> {
+    #[inline]
+    pub(crate) fn new(initial_value: T) -> Self {
+        Self::new_with(Some(initial_value))
+    }
+
+    #[inline]
+    pub(crate) fn uninit() -> Self {
+        Self::new_with(None)
+    }
+
+    fn new_with(data: Option<T>) -> Self {
+        Self {
+            state: Cell::new(SignalState::Idling),
+            data: UnsafeCell::new(data),
+            subscribers: UnsafeCell::new(Vec::new()),
+            next_id: Cell::new(NonZeroU32::new(1).unwrap()),
+            needs_delete: Cell::new(false),
+            dependencies: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Keeps `dependency` alive for as long as this signal is. Derived
+    /// signals use this to hold on to the [`Unsubscriber`](super::Unsubscriber)
+    /// of the parent(s) they depend on, so the subscription dies with them.
+    pub(crate) fn keep_alive(&self, dependency: impl Any) {
+        self.dependencies.borrow_mut().push(Box::new(dependency));
+    }
+
+    pub(crate) fn try_get(&self) -> Result<T>
+    where
+        T: Clone,
+    {
+        // SAFETY: we never hand out a reference to `data` across a call that
+        // could re-enter this signal, so a shared read here is sound.
+        let data = unsafe { &*self.data.get() };
+        data.clone().ok_or(Error::Uninitialized)
+    }
+
+    pub(crate) fn try_set(&self, new_value: T) -> Result<()> {
+        if self.state.get() != SignalState::Idling {
+            return Err(Error::Busy);
+        }
+
+        self.state.set(SignalState::Mutating);
+
+        // SAFETY: the state guard above prevents any other live access to
+        // `data` while we hold this mutable reference.
+        let data = unsafe { &mut *self.data.get() };
+        *data = Some(new_value);
+
+        // SAFETY: we just proved `data` holds a value, and we set the state
+        // to `Mutating`, so this is the only call to `notify_all` in flight.
+        unsafe { self.notify_all(data.as_ref().unwrap()) };
+
+        self.state.set(SignalState::Idling);
+        Ok(())
+    }
+
+    pub(crate) fn try_mutate(&self, mutate: impl FnOnce(&mut T)) -> Result<()> {
+        if self.state.get() != SignalState::Idling {
+            return Err(Error::Busy);
+        }
+
+        self.state.set(SignalState::Mutating);
+
+        // SAFETY: see `try_set`.
+        let data = unsafe { &mut *self.data.get() };
+        let Some(value) = data.as_mut() else {
+            self.state.set(SignalState::Idling);
+            return Err(Error::Uninitialized);
+        };
+        mutate(value);
+
+        // SAFETY: see `try_set`.
+        unsafe { self.notify_all(data.as_ref().unwrap()) };
+
+        self.state.set(SignalState::Idling);
+        Ok(())
+    }
+
+    /// # Safety
+    ///
+    /// The caller must ensure that no contained `self.subscribers[i].notify` is
+    /// currently being called or dropped.
+    ///
+    /// Subscribers added while this runs (e.g. by dependency tracking) are
+    /// not called for `value`, only for later updates.
+    unsafe fn notify_all(&self, value: &T) {
+        let subscribers = self.subscribers.get();
+        let len = (*subscribers).len();
+        let mut i = 0;
+
+        while i < len {
+            let notifier = (*subscribers).as_mut_ptr().add(i).as_ref().unwrap();
+
+            if !notifier.state.get().deleted() {
+                (*notifier.notify)(value);
+            }
+
+            i += 1;
+        }
+
+        if self.needs_delete.take() {
+            (*subscribers).retain(|notifier| !notifier.state.get().deleted());
+        }
+    }
+
+    /// Registers a subscriber built from the [`SubscriberId`] it is about to be
+    /// given, running it once immediately with the current value (unless the
+    /// signal is uninitialized or already mutating), then on every future
+    /// update.
+    pub(crate) fn raw_for_each<F, M>(&self, make: M) -> SubscriberId
+    where
+        M: FnOnce(SubscriberId) -> F,
+        F: FnMut(&T) + 'static,
+    {
+        let id = self.next_subscriber_id();
+        let mut notify = make(id);
+
+        if self.state.get() != SignalState::Mutating {
+            // SAFETY: see `try_set`; we are not `Mutating` so no other borrow
+            // of `data` is alive.
+            if let Some(value) = unsafe { &*self.data.get() }.as_ref() {
+                let old_state = self.state.replace(SignalState::Subscribing);
+                notify(value);
+                self.state.set(old_state);
+            }
+        }
+
+        self.push_subscriber(id, notify);
+        id
+    }
+
+    /// Registers `notify` as a subscriber without running it immediately,
+    /// unlike [`Self::raw_for_each`]. Used by dependency tracking, which
+    /// subscribes from inside a read that is itself running as a reaction to
+    /// a previous notification, and must not re-enter synchronously.
+    pub(crate) fn subscribe_silent<F>(&self, notify: F) -> SubscriberId
+    where
+        F: FnMut(&T) + 'static,
+    {
+        let id = self.next_subscriber_id();
+        self.push_subscriber(id, notify);
+        id
+    }
+
+    fn next_subscriber_id(&self) -> SubscriberId {
+        let id = SubscriberId(self.next_id.get());
+        self.next_id.set(NonZeroU32::new(id.0.get() + 1).unwrap());
+        id
+    }
+
+    fn push_subscriber(&self, id: SubscriberId, notify: impl FnMut(&T) + 'static) {
+        let subscribers = self.subscribers.get();
+        // SAFETY: this can run from inside a subscriber that `notify_all` is
+        // calling (dependency tracking subscribes from there), and the push
+        // may reallocate the vec. Each closure lives in its own box, which
+        // does not move, and `notify_all` re-reads the vec on every
+        // iteration, so no reference into the old buffer is used afterwards.
+        unsafe {
+            (*subscribers).push(Notifier {
+                state: Cell::new(NotifierState::Active(id)),
+                notify: Box::into_raw(Box::new(notify)),
+            });
+        }
+    }
+
+    pub(crate) fn unsubscribe(&self, id: SubscriberId) {
+        let subscribers = self.subscribers.get();
+
+        // SAFETY: no other live borrow of `subscribers` can overlap this one;
+        // `next_id` only ever increases and `retain` preserves order, so the
+        // vec stays sorted by id and a binary search is valid.
+        unsafe {
+            if let Ok(index) = (*subscribers).binary_search_by_key(&id, |n| n.state.get().id()) {
+                if self.state.get() == SignalState::Mutating {
+                    (&mut *subscribers)[index].state.set(NotifierState::Deleted(id));
+                    self.needs_delete.set(true);
+                } else {
+                    (*subscribers).remove(index);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use super::{Signal, Unsubscriber};
+
+/// A type that can be treated as an observable source of `T`s: either a
+/// [`Signal<T>`], or a plain `T` acting as a constant, never-changing one.
+pub trait Value<T> {
+    fn for_each(&self, f: impl FnMut(&T) + 'static) -> Unsubscriber<T>;
+}
+
+impl<T: Clone + 'static> Value<T> for T {
+    #[inline]
+    fn for_each(&self, mut f: impl FnMut(&T) + 'static) -> Unsubscriber<T> {
+        f(self);
+        Unsubscriber(None)
+    }
+}
+
+impl<T: 'static> Value<T> for Signal<T> {
+    #[inline]
+    fn for_each(&self, f: impl FnMut(&T) + 'static) -> Unsubscriber<T> {
+        Signal::for_each(self, f)
+    }
+}